@@ -0,0 +1,43 @@
+use std::fmt::{Display, Formatter};
+
+/// The DLL storage class records whether a [Global] is imported from, or exported to, a Windows
+/// DLL, independently of its [Linkage](crate::attributes::linkage::Linkage). Older LLVM IR folded
+/// this into dedicated ``DLLImportLinkage``/``DLLExportLinkage`` [Linkage](crate::attributes::linkage::Linkage)
+/// values; modern IR keeps it as its own attribute so the two combine freely, e.g.
+/// `external dllexport`.
+///
+/// [Global]: crate::module_elements::Global
+#[derive(Debug,Eq,PartialEq)]
+pub enum DllStorageClass {
+    /// The [Global] has no particular DLL storage class.
+    Default,
+
+    /// The [Global] is imported from a DLL.
+    DllImport,
+
+    /// The [Global] is exported from this [module][crate::module_elements::module::Module] as a
+    /// DLL.
+    DllExport,
+}
+
+impl Display for DllStorageClass {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str( match self {
+            DllStorageClass::Default => "",
+            DllStorageClass::DllImport => "dllimport",
+            DllStorageClass::DllExport => "dllexport",
+        })
+    }
+}
+
+#[cfg(test)]
+pub mod dll_storage_class_tests {
+    use crate::attributes::dll_storage_class::DllStorageClass::*;
+
+    #[test]
+    fn test_stringify() {
+        assert_eq!(format!("{}", DllImport), "dllimport");
+        assert_eq!(format!("{}", DllExport), "dllexport");
+        assert_eq!(format!("{}", Default), "");
+    }
+}
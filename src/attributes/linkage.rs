@@ -1,4 +1,5 @@
 use std::fmt::{Display, Formatter};
+use std::str::FromStr;
 
 #[derive(Debug,Eq,PartialEq)]
 pub enum Linkage {
@@ -71,6 +72,75 @@ pub enum Linkage {
 
     /// If none of the above identifiers are used, the ``Global`` is externally visible, meaning that it participates in linkage and can be used to resolve external symbol references.
     External,
+
+    /// Similar to [LinkOnceODR](Linkage::LinkOnceODR), but it indicates that the address is not
+    /// significant and the ``Global`` is known not to be referenced by its address, so the
+    /// symbol may be hidden once it has been linked.
+    LinkOnceODRAutoHide,
+
+    /// ``LinkerPrivate`` is like [Private](Linkage::Private), except that some targets may rename
+    /// the symbol to avoid it colliding with a reserved name. It is meant to be used by the linker
+    /// and code generator internally and is not otherwise meaningful to the language.
+    LinkerPrivate,
+
+    /// ``LinkerPrivateWeak`` is like ``LinkerPrivate``, but with ``Weak`` linkage semantics. It is
+    /// likewise meant to be used by the linker and code generator internally.
+    LinkerPrivateWeak,
+}
+
+impl Linkage {
+    /// True for linkage kinds whose symbol is only visible within the current
+    /// [Module](crate::module_elements::module::Module): [Private](Linkage::Private) and
+    /// [Internal](Linkage::Internal).
+    pub fn is_local(&self) -> bool {
+        matches!(self, Linkage::Private | Linkage::Internal)
+    }
+
+    /// True for linkage kinds whose [Global] may be discarded if nothing in the
+    /// [Module](crate::module_elements::module::Module) references it:
+    /// [LinkOnce](Linkage::LinkOnce), [LinkOnceODR](Linkage::LinkOnceODR),
+    /// [AvailableExternally](Linkage::AvailableExternally), and the
+    /// [local](Linkage::is_local) linkages. [Weak](Linkage::Weak), [WeakODR](Linkage::WeakODR), and
+    /// [Common](Linkage::Common) are *not* discardable: an unreferenced weak symbol may still be
+    /// required to satisfy another translation unit's reference to it.
+    ///
+    /// [Global]: crate::module_elements::Global
+    pub fn is_discardable_if_unused(&self) -> bool {
+        matches!(
+            self,
+            Linkage::LinkOnce
+                | Linkage::LinkOnceODR
+                | Linkage::AvailableExternally
+                | Linkage::Private
+                | Linkage::Internal
+        )
+    }
+
+    /// True for linkage kinds that the linker may need to merge or drop rather than treat as a
+    /// single definitive definition: the weak, link-once, [Common](Linkage::Common), and
+    /// [ExternWeak](Linkage::ExternWeak) family.
+    pub fn is_weak_for_linker(&self) -> bool {
+        matches!(
+            self,
+            Linkage::Weak
+                | Linkage::WeakODR
+                | Linkage::LinkOnce
+                | Linkage::LinkOnceODR
+                | Linkage::LinkOnceODRAutoHide
+                | Linkage::Common
+                | Linkage::ExternWeak
+                | Linkage::LinkerPrivateWeak
+        )
+    }
+
+    /// True for linkage kinds that guarantee any two definitions of the symbol are equivalent
+    /// (the “one definition rule”): [LinkOnceODR](Linkage::LinkOnceODR) and
+    /// [WeakODR](Linkage::WeakODR). Unlike their non-ODR counterparts, these may safely be
+    /// inlined or otherwise optimized on the assumption that the definition used is
+    /// interchangeable with any other.
+    pub fn is_odr(&self) -> bool {
+        matches!(self, Linkage::LinkOnceODR | Linkage::WeakODR)
+    }
 }
 
 impl Display for Linkage {
@@ -79,14 +149,54 @@ impl Display for Linkage {
             Linkage::Private => "private",
             Linkage::Internal => "internal",
             Linkage::AvailableExternally => "available_externally",
-            Linkage::LinkOnce => "link_once",
+            Linkage::LinkOnce => "linkonce",
             Linkage::Weak => "weak",
             Linkage::Common => "common",
             Linkage::Appending => "appending",
             Linkage::ExternWeak => "extern_weak",
-            Linkage::LinkOnceODR => "link_once_odr",
+            Linkage::LinkOnceODR => "linkonce_odr",
             Linkage::WeakODR => "weak_odr",
             Linkage::External => "external",
+            Linkage::LinkOnceODRAutoHide => "linkonce_odr_auto_hide",
+            Linkage::LinkerPrivate => "linker_private",
+            Linkage::LinkerPrivateWeak => "linker_private_weak",
+        })
+    }
+}
+
+/// The keyword was not one of the textual linkage types emitted by [Display for
+/// Linkage](Linkage).
+#[derive(Debug,Eq,PartialEq)]
+pub struct ParseLinkageError(String);
+
+impl Display for ParseLinkageError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "`{}` is not a valid linkage keyword", self.0)
+    }
+}
+
+impl std::error::Error for ParseLinkageError {}
+
+impl FromStr for Linkage {
+    type Err = ParseLinkageError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "private" => Linkage::Private,
+            "internal" => Linkage::Internal,
+            "available_externally" => Linkage::AvailableExternally,
+            "linkonce" => Linkage::LinkOnce,
+            "weak" => Linkage::Weak,
+            "common" => Linkage::Common,
+            "appending" => Linkage::Appending,
+            "extern_weak" => Linkage::ExternWeak,
+            "linkonce_odr" => Linkage::LinkOnceODR,
+            "weak_odr" => Linkage::WeakODR,
+            "external" => Linkage::External,
+            "linkonce_odr_auto_hide" => Linkage::LinkOnceODRAutoHide,
+            "linker_private" => Linkage::LinkerPrivate,
+            "linker_private_weak" => Linkage::LinkerPrivateWeak,
+            _ => return Err(ParseLinkageError(s.to_string())),
         })
     }
 }
@@ -94,10 +204,67 @@ impl Display for Linkage {
 #[cfg(test)]
 pub mod linkage_tests {
     use crate::attributes::linkage::Linkage::*;
+    use crate::attributes::linkage::Linkage;
 
     #[test]
     fn test_stringify() {
-        assert_eq!(format!("{}", LinkOnce), "link_once");
+        assert_eq!(format!("{}", LinkOnce), "linkonce");
         assert_eq!(format!("{}", External), "external");
     }
+
+    #[test]
+    fn test_parse_round_trip() {
+        let variants = [
+            Private, Internal, AvailableExternally, LinkOnce, Weak, Common, Appending,
+            ExternWeak, LinkOnceODR, WeakODR, External, LinkOnceODRAutoHide, LinkerPrivate,
+            LinkerPrivateWeak,
+        ];
+
+        for variant in variants {
+            assert_eq!(format!("{}", variant).parse::<Linkage>().unwrap(), variant);
+        }
+    }
+
+    #[test]
+    fn test_parse_unknown_keyword() {
+        assert!("not_a_linkage".parse::<Linkage>().is_err());
+    }
+
+    #[test]
+    fn test_is_local() {
+        assert!(Private.is_local());
+        assert!(Internal.is_local());
+        assert!(!External.is_local());
+    }
+
+    #[test]
+    fn test_is_discardable_if_unused() {
+        assert!(LinkOnce.is_discardable_if_unused());
+        assert!(LinkOnceODR.is_discardable_if_unused());
+        assert!(AvailableExternally.is_discardable_if_unused());
+        assert!(Private.is_discardable_if_unused());
+        assert!(Internal.is_discardable_if_unused());
+        assert!(!Weak.is_discardable_if_unused());
+        assert!(!WeakODR.is_discardable_if_unused());
+        assert!(!Common.is_discardable_if_unused());
+    }
+
+    #[test]
+    fn test_is_weak_for_linker() {
+        assert!(Weak.is_weak_for_linker());
+        assert!(WeakODR.is_weak_for_linker());
+        assert!(LinkOnce.is_weak_for_linker());
+        assert!(LinkOnceODR.is_weak_for_linker());
+        assert!(Common.is_weak_for_linker());
+        assert!(ExternWeak.is_weak_for_linker());
+        assert!(!External.is_weak_for_linker());
+    }
+
+    #[test]
+    fn test_is_odr() {
+        assert!(LinkOnceODR.is_odr());
+        assert!(WeakODR.is_odr());
+        assert!(!LinkOnce.is_odr());
+        assert!(!Weak.is_odr());
+    }
 }
\ No newline at end of file
@@ -0,0 +1,50 @@
+use std::fmt::{Display, Formatter};
+
+/// Visibility styles control how a [Global] is treated once linked, independently of its
+/// [Linkage](crate::attributes::linkage::Linkage). See `GlobalValue::VisibilityTypes` in LLVM's
+/// `GlobalValue.h`.
+///
+/// [Global]: crate::module_elements::Global
+#[derive(Debug,Eq,PartialEq)]
+pub enum Visibility {
+    /// On targets that use the ELF object file format, default visibility means that the
+    /// declaration is visible to other modules and, in shared libraries, means that the declared
+    /// entity may be overridden. On Darwin, default visibility means that the declaration is
+    /// visible to other modules. Default visibility corresponds to “external linkage” in the
+    /// language.
+    Default,
+
+    /// Two declarations of an object with ``Hidden`` visibility refer to the same object if they
+    /// are in the same shared object. Usually, hidden visibility indicates that the symbol will
+    /// not be placed into the dynamic symbol table, so no other module (executable or shared
+    /// library) can reference it directly.
+    Hidden,
+
+    /// On targets that use the ELF object file format, ``Protected`` visibility indicates that the
+    /// symbol will be placed in the dynamic symbol table, but that references within the defining
+    /// module will bind to the local symbol, i.e. the symbol cannot be overridden by another
+    /// module.
+    Protected,
+}
+
+impl Display for Visibility {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str( match self {
+            Visibility::Default => "",
+            Visibility::Hidden => "hidden",
+            Visibility::Protected => "protected",
+        })
+    }
+}
+
+#[cfg(test)]
+pub mod visibility_tests {
+    use crate::attributes::visibility::Visibility::*;
+
+    #[test]
+    fn test_stringify() {
+        assert_eq!(format!("{}", Hidden), "hidden");
+        assert_eq!(format!("{}", Protected), "protected");
+        assert_eq!(format!("{}", Default), "");
+    }
+}
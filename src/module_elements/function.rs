@@ -0,0 +1,84 @@
+use std::fmt::{Display, Formatter};
+use crate::attributes::linkage::Linkage;
+use crate::attributes::visibility::Visibility;
+use crate::attributes::dll_storage_class::DllStorageClass;
+use crate::module_elements::render::attr_prefix;
+
+/// A function declaration or definition (see `Function` in LLVM's `Function.h`). Like
+/// [GlobalVariable](crate::module_elements::global::GlobalVariable), every [Function] carries a
+/// [Linkage], a [Visibility], and a [DllStorageClass].
+#[derive(Debug, Eq, PartialEq)]
+pub struct Function {
+    name: String,
+    linkage: Linkage,
+    visibility: Visibility,
+    dll_storage_class: DllStorageClass,
+    return_type: String,
+    is_declaration: bool,
+}
+
+impl Function {
+    pub fn new(name: String, linkage: Linkage, return_type: String) -> Self {
+        Function {
+            name,
+            linkage,
+            visibility: Visibility::Default,
+            dll_storage_class: DllStorageClass::Default,
+            return_type,
+            is_declaration: true,
+        }
+    }
+
+    pub fn with_visibility(mut self, visibility: Visibility) -> Self {
+        self.visibility = visibility;
+        self
+    }
+
+    pub fn with_dll_storage_class(mut self, dll_storage_class: DllStorageClass) -> Self {
+        self.dll_storage_class = dll_storage_class;
+        self
+    }
+
+    /// Marks the [Function] as a definition rather than a declaration, i.e. `define` rather than
+    /// `declare`.
+    pub fn define(mut self) -> Self {
+        self.is_declaration = false;
+        self
+    }
+}
+
+impl Display for Function {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} {}",
+            if self.is_declaration { "declare" } else { "define" },
+            attr_prefix(&self.linkage, &self.visibility, &self.dll_storage_class))?;
+
+        write!(f, " {} @{}()", self.return_type, self.name)
+    }
+}
+
+#[cfg(test)]
+mod function_tests {
+    use crate::attributes::linkage::Linkage;
+    use crate::attributes::visibility::Visibility;
+    use crate::module_elements::function::Function;
+    use crate::str;
+
+    #[test]
+    fn display_external_declaration() {
+        let function = Function::new(str!("f"), Linkage::External, str!("void"));
+
+        assert_eq!(format!("{}", function), "declare external void @f()");
+    }
+
+    #[test]
+    fn display_defined_function_with_visibility() {
+        let function = Function::new(str!("f"), Linkage::External, str!("void"))
+            .with_visibility(Visibility::Hidden)
+            .define();
+
+        assert_eq!(format!("{}", function), "define external hidden void @f()");
+    }
+}
@@ -0,0 +1,91 @@
+use std::fmt::{Display, Formatter};
+use crate::attributes::linkage::Linkage;
+use crate::attributes::visibility::Visibility;
+use crate::attributes::dll_storage_class::DllStorageClass;
+use crate::module_elements::render::attr_prefix;
+
+/// A global variable definition or declaration (see `GlobalVariable` in LLVM's
+/// `GlobalVariable.h`). Like every LLVM global value, a [GlobalVariable] carries a [Linkage], a
+/// [Visibility], and a [DllStorageClass] that together control how it participates in linking.
+#[derive(Debug, Eq, PartialEq)]
+pub struct GlobalVariable {
+    name: String,
+    linkage: Linkage,
+    visibility: Visibility,
+    dll_storage_class: DllStorageClass,
+    is_constant: bool,
+    ty: String,
+    initializer: Option<String>,
+}
+
+impl GlobalVariable {
+    pub fn new(name: String, linkage: Linkage, ty: String) -> Self {
+        GlobalVariable {
+            name,
+            linkage,
+            visibility: Visibility::Default,
+            dll_storage_class: DllStorageClass::Default,
+            is_constant: false,
+            ty,
+            initializer: None,
+        }
+    }
+
+    pub fn with_visibility(mut self, visibility: Visibility) -> Self {
+        self.visibility = visibility;
+        self
+    }
+
+    pub fn with_dll_storage_class(mut self, dll_storage_class: DllStorageClass) -> Self {
+        self.dll_storage_class = dll_storage_class;
+        self
+    }
+
+    pub fn with_initializer(mut self, initializer: String) -> Self {
+        self.initializer = Some(initializer);
+        self
+    }
+
+    pub fn make_constant(mut self) -> Self {
+        self.is_constant = true;
+        self
+    }
+}
+
+impl Display for GlobalVariable {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "@{} = {}", self.name, attr_prefix(&self.linkage, &self.visibility, &self.dll_storage_class))?;
+        write!(f, " {} {}", if self.is_constant { "constant" } else { "global" }, self.ty)?;
+
+        if let Some(initializer) = &self.initializer {
+            write!(f, " {}", initializer)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod global_tests {
+    use crate::attributes::dll_storage_class::DllStorageClass;
+    use crate::attributes::linkage::Linkage;
+    use crate::module_elements::global::GlobalVariable;
+    use crate::str;
+
+    #[test]
+    fn display_internal_global_with_initializer() {
+        let global = GlobalVariable::new(str!("x"), Linkage::Internal, str!("i32"))
+            .with_initializer(str!("0"));
+
+        assert_eq!(format!("{}", global), "@x = internal global i32 0");
+    }
+
+    #[test]
+    fn display_external_dllexport_constant() {
+        let global = GlobalVariable::new(str!("x"), Linkage::External, str!("i32"))
+            .with_dll_storage_class(DllStorageClass::DllExport)
+            .make_constant();
+
+        assert_eq!(format!("{}", global), "@x = external dllexport constant i32");
+    }
+}
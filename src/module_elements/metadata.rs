@@ -0,0 +1,35 @@
+use std::fmt::{Display, Formatter};
+
+/// A named metadata node (see `NamedMDNode` in LLVM's `Metadata.h`), e.g.
+/// `!llvm.module.flags = !{!0, !1}`. Named metadata has no [Linkage](crate::attributes::linkage::Linkage)
+/// of its own; it simply groups a name with a list of metadata operands.
+#[derive(Debug, Eq, PartialEq)]
+pub struct Metadata {
+    name: String,
+    operands: Vec<String>,
+}
+
+impl Metadata {
+    pub fn new(name: String, operands: Vec<String>) -> Self {
+        Metadata { name, operands }
+    }
+}
+
+impl Display for Metadata {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "!{} = !{{{}}}", self.name, self.operands.join(", "))
+    }
+}
+
+#[cfg(test)]
+mod metadata_tests {
+    use crate::module_elements::metadata::Metadata;
+    use crate::str;
+
+    #[test]
+    fn display_named_metadata() {
+        let metadata = Metadata::new(str!("llvm.module.flags"), vec![str!("!0"), str!("!1")]);
+
+        assert_eq!(format!("{}", metadata), "!llvm.module.flags = !{!0, !1}");
+    }
+}
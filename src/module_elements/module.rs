@@ -3,6 +3,7 @@ use crate::module_elements::{
     metadata::*,
     function::*
 };
+use std::fmt::{Display, Formatter};
 
 #[derive(Debug, Eq, PartialEq)]
 pub struct Module {
@@ -16,10 +17,83 @@ impl Module {
     pub fn new(name: String) -> Self {
         Module { name, functions: vec![], globals: vec![], metadata: vec![] }
     }
+
+    /// Appends a [Function] to the [Module], in the order it should be emitted.
+    pub fn add_function(&mut self, function: Function) -> &mut Self {
+        self.functions.push(function);
+        self
+    }
+
+    /// Appends a [GlobalVariable] to the [Module], in the order it should be emitted.
+    pub fn add_global(&mut self, global: GlobalVariable) -> &mut Self {
+        self.globals.push(global);
+        self
+    }
+
+    /// Appends a named [Metadata] node to the [Module].
+    pub fn add_named_metadata(&mut self, metadata: Metadata) -> &mut Self {
+        self.metadata.push(metadata);
+        self
+    }
+
+    /// The [Function]s contained in the [Module], in emission order.
+    pub fn functions(&self) -> impl Iterator<Item = &Function> {
+        self.functions.iter()
+    }
+
+    /// The [GlobalVariable]s contained in the [Module], in emission order.
+    pub fn globals(&self) -> impl Iterator<Item = &GlobalVariable> {
+        self.globals.iter()
+    }
+
+    /// The named [Metadata] nodes contained in the [Module], in emission order.
+    pub fn named_metadata(&self) -> impl Iterator<Item = &Metadata> {
+        self.metadata.iter()
+    }
+}
+
+impl Display for Module {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "; ModuleID = '{}'", self.name)?;
+
+        let mut wrote_section = false;
+
+        if !self.globals.is_empty() {
+            for global in &self.globals {
+                writeln!(f, "{}", global)?;
+            }
+            wrote_section = true;
+        }
+
+        if !self.functions.is_empty() {
+            if wrote_section {
+                writeln!(f)?;
+            }
+            for function in &self.functions {
+                writeln!(f, "{}", function)?;
+            }
+            wrote_section = true;
+        }
+
+        if !self.metadata.is_empty() {
+            if wrote_section {
+                writeln!(f)?;
+            }
+            for metadata in &self.metadata {
+                writeln!(f, "{}", metadata)?;
+            }
+        }
+
+        Ok(())
+    }
 }
 
 #[cfg(test)]
 mod module_tests {
+    use crate::attributes::linkage::Linkage;
+    use crate::module_elements::function::Function;
+    use crate::module_elements::global::GlobalVariable;
+    use crate::module_elements::metadata::Metadata;
     use crate::module_elements::module::Module;
     use crate::str;
 
@@ -29,4 +103,42 @@ mod module_tests {
             Module::new(str!("main")),
             Module {name: str!("main"), functions: vec![], globals: vec![], metadata: vec![] })
     }
+
+    #[test]
+    fn display_empty_module() {
+        assert_eq!(format!("{}", Module::new(str!("main"))), "; ModuleID = 'main'\n");
+    }
+
+    #[test]
+    fn display_module_with_globals_functions_and_metadata() {
+        let mut module = Module::new(str!("main"));
+        module.add_global(
+            GlobalVariable::new(str!("x"), Linkage::Internal, str!("i32"))
+                .with_initializer(str!("0")));
+        module.add_function(Function::new(str!("f"), Linkage::External, str!("void")));
+        module.add_named_metadata(Metadata::new(str!("llvm.module.flags"), vec![str!("!0")]));
+
+        assert_eq!(
+            format!("{}", module),
+            "; ModuleID = 'main'\n\
+             @x = internal global i32 0\n\
+             \n\
+             declare external void @f()\n\
+             \n\
+             !llvm.module.flags = !{!0}\n");
+    }
+
+    #[test]
+    fn display_module_skips_separator_for_empty_middle_section() {
+        let mut module = Module::new(str!("main"));
+        module.add_global(GlobalVariable::new(str!("x"), Linkage::Internal, str!("i32")));
+        module.add_named_metadata(Metadata::new(str!("llvm.module.flags"), vec![str!("!0")]));
+
+        assert_eq!(
+            format!("{}", module),
+            "; ModuleID = 'main'\n\
+             @x = internal global i32\n\
+             \n\
+             !llvm.module.flags = !{!0}\n");
+    }
 }
\ No newline at end of file
@@ -0,0 +1,49 @@
+use std::fmt::Display;
+
+/// Joins a [Global]'s [Linkage](crate::attributes::linkage::Linkage),
+/// [Visibility](crate::attributes::visibility::Visibility), and
+/// [DllStorageClass](crate::attributes::dll_storage_class::DllStorageClass) into the
+/// space-separated prefix LLVM expects before the `global`/`constant` keyword or after the
+/// `declare`/`define` keyword, omitting any attribute whose `Display` is empty (the default value for `Visibility`
+/// and `DllStorageClass`).
+///
+/// [Global]: crate::module_elements::Global
+pub(crate) fn attr_prefix(
+    linkage: &impl Display,
+    visibility: &impl Display,
+    dll_storage_class: &impl Display,
+) -> String {
+    let mut parts = vec![linkage.to_string()];
+
+    let visibility = visibility.to_string();
+    if !visibility.is_empty() {
+        parts.push(visibility);
+    }
+
+    let dll_storage_class = dll_storage_class.to_string();
+    if !dll_storage_class.is_empty() {
+        parts.push(dll_storage_class);
+    }
+
+    parts.join(" ")
+}
+
+#[cfg(test)]
+mod render_tests {
+    use crate::attributes::dll_storage_class::DllStorageClass;
+    use crate::attributes::linkage::Linkage;
+    use crate::attributes::visibility::Visibility;
+    use crate::module_elements::render::attr_prefix;
+
+    #[test]
+    fn omits_default_visibility_and_dll_storage_class() {
+        assert_eq!(attr_prefix(&Linkage::Internal, &Visibility::Default, &DllStorageClass::Default), "internal");
+    }
+
+    #[test]
+    fn includes_non_default_attributes_in_order() {
+        assert_eq!(
+            attr_prefix(&Linkage::External, &Visibility::Hidden, &DllStorageClass::DllExport),
+            "external hidden dllexport");
+    }
+}